@@ -1,4 +1,4 @@
-use secret_box::{ExposeSecret, SecretBox};
+use secret_box::{ExposeSecret, SecretBox, SerdeSecret};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -19,4 +19,8 @@ fn main() {
 
     // Note: Cannot serialize SecretBox<String> without implementing SerializableSecret
     // This is intentional to prevent accidental secret leakage
+
+    // To serialize at a specific call site instead, wrap the reference:
+    let reexported = serde_json::to_string(&SerdeSecret(&config.api_key)).unwrap();
+    println!("Re-exported for a trusted sink: {}", reexported);
 }
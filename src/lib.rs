@@ -13,12 +13,30 @@
 //! # Features
 //!
 //! - `serde`: Enable serialization/deserialization support
+//! - `mlock`: Pin a secret's heap allocation with `mlock(2)` so it cannot be
+//!   paged to swap (unix only; pulls in `std` and requires the `libc` crate)
+//! - `cloneable-secret`: Allow cloning secret types that opt in via
+//!   [`CloneableSecret`]
+//! - `exposable-secret`: Enable [`ExposableSecret`], a const-generic secret
+//!   that limits how many times it can be exposed (nightly only)
 
-#![no_std]
+#![cfg_attr(not(feature = "mlock"), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
-#![forbid(unsafe_code)]
+#![cfg_attr(feature = "exposable-secret", feature(generic_const_exprs))]
+#![cfg_attr(feature = "exposable-secret", allow(incomplete_features))]
+#![cfg_attr(not(feature = "mlock"), forbid(unsafe_code))]
+#![cfg_attr(feature = "mlock", deny(unsafe_code))]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+#[cfg(all(feature = "mlock", not(unix)))]
+compile_error!("the `mlock` feature is only supported on unix platforms");
+
+#[cfg(feature = "mlock")]
+mod mlock;
+
+#[cfg(feature = "mlock")]
+pub use mlock::{Lockable, MlockError};
+
 extern crate alloc;
 
 use alloc::boxed::Box;
@@ -27,6 +45,7 @@ use alloc::vec::Vec;
 use core::{
     any,
     fmt::{self, Debug},
+    marker::PhantomData,
 };
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -35,12 +54,40 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub use zeroize;
 
+/// Marker trait for types used to tag a [`SecretBox`] with a secret category.
+///
+/// Implementing this on a zero-sized marker type lets callers distinguish, at
+/// compile time, secrets that are both `SecretBox<String>` but belong to
+/// different domains (e.g. an API key vs. a database password), so one can't
+/// be passed where the other is expected. The label only affects the type; it
+/// has no effect on zeroization or redaction behavior.
+///
+/// # Example
+///
+/// ```
+/// use secret_box::{SecretBox, SecretLabel, ExposeSecret};
+///
+/// struct ApiKey;
+/// impl SecretLabel for ApiKey {}
+///
+/// let key: SecretBox<String, ApiKey> = "my_api_key".to_string().into();
+/// assert_eq!(key.expose_secret(), "my_api_key");
+/// ```
+pub trait SecretLabel {}
+
+impl SecretLabel for () {}
+
 /// Wrapper type for values that contain secrets, which attempts to limit
 /// accidental exposure and ensure secrets are wiped from memory when dropped.
 ///
 /// Access to the secret inner value occurs through the [`ExposeSecret`] trait,
 /// which provides a method for accessing the inner secret value.
 ///
+/// The optional `L` parameter tags the secret with a [`SecretLabel`] so that,
+/// for example, an API key and a database password cannot be accidentally
+/// passed to the wrong function even though both are `SecretBox<String>`. It
+/// defaults to `()`, so existing code naming just `SecretBox<S>` is unaffected.
+///
 /// # Example
 ///
 /// ```
@@ -56,16 +103,21 @@ pub use zeroize;
 /// let debug_output = format!("{:?}", password);
 /// assert!(!debug_output.contains("super_secret"));
 /// ```
-pub struct SecretBox<S: Zeroize> {
+pub struct SecretBox<S: Zeroize, L: SecretLabel = ()> {
     inner: Box<S>,
     length: Option<usize>,
+    _label: PhantomData<L>,
+    #[cfg(feature = "mlock")]
+    unlock_fn: Option<fn(&S)>,
 }
 
 impl<S: Zeroize> SecretBox<S> {
     /// Create a secret value using a pre-boxed value.
     ///
     /// This is the primary constructor. The value must already be on the heap
-    /// (in a `Box`) to avoid leaving copies on the stack.
+    /// (in a `Box`) to avoid leaving copies on the stack. The secret is tagged
+    /// with the default `()` label; use [`SecretBox::new_labeled`] to tag it
+    /// with a specific [`SecretLabel`] instead.
     ///
     /// # Example
     ///
@@ -75,9 +127,104 @@ impl<S: Zeroize> SecretBox<S> {
     /// let secret = SecretBox::new(Box::new("password".to_string()));
     /// ```
     pub fn new(boxed: Box<S>) -> Self {
+        Self::new_labeled(boxed)
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl<S: Zeroize + Lockable> SecretBox<S> {
+    /// Create a secret value using a pre-boxed value, pinning its *actual*
+    /// secret bytes with `mlock(2)` so they cannot be paged to swap.
+    ///
+    /// The secret is tagged with the default `()` label; use
+    /// [`SecretBox::new_locked_labeled`] to tag it with a specific
+    /// [`SecretLabel`] instead.
+    ///
+    /// Returns an error if `mlock(2)` fails, which can happen if the process
+    /// has already locked as much memory as its `RLIMIT_MEMLOCK` allows. The
+    /// region is `munlock`'d on drop, before it is zeroized.
+    ///
+    /// This requires `S: Lockable` because the heap region that needs
+    /// pinning depends on `S`'s layout: for `String`/`Vec<u8>` (implemented
+    /// in this crate) that's the separate allocation backing the
+    /// string/vector, not the inline `SecretBox` header. See [`Lockable`] to
+    /// support other types.
+    ///
+    /// Note: mutating the secret afterwards through [`ExposeSecretMut`] can
+    /// silently invalidate this guarantee. `String`/`Vec<u8>` may reallocate
+    /// on mutation, moving the secret bytes to a new allocation that is not
+    /// locked, while the old locked allocation is freed without being
+    /// `munlock`'d. Nothing re-pins a secret after such a mutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secret_box::{SecretBox, ExposeSecret};
+    ///
+    /// let secret = SecretBox::new_locked(Box::new("super_secret".to_string()))
+    ///     .expect("mlock should succeed in test environments");
+    /// assert_eq!(secret.expose_secret(), "super_secret");
+    /// ```
+    pub fn new_locked(boxed: Box<S>) -> Result<Self, MlockError> {
+        Self::new_locked_labeled(boxed)
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl<S: Zeroize + Lockable, L: SecretLabel> SecretBox<S, L> {
+    /// Create a secret value tagged with an explicit [`SecretLabel`], using a
+    /// pre-boxed value, pinning its *actual* secret bytes with `mlock(2)` so
+    /// they cannot be paged to swap.
+    ///
+    /// See [`SecretBox::new_locked`] for the full behavior and caveats; this
+    /// is the labeled counterpart, mirroring [`SecretBox::new_labeled`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secret_box::{SecretBox, ExposeSecret, SecretLabel};
+    ///
+    /// struct ApiKey;
+    /// impl SecretLabel for ApiKey {}
+    ///
+    /// let secret: SecretBox<String, ApiKey> =
+    ///     SecretBox::new_locked_labeled(Box::new("super_secret".to_string()))
+    ///         .expect("mlock should succeed in test environments");
+    /// assert_eq!(secret.expose_secret(), "super_secret");
+    /// ```
+    pub fn new_locked_labeled(boxed: Box<S>) -> Result<Self, MlockError> {
+        mlock::lock(&*boxed)?;
+        Ok(Self {
+            inner: boxed,
+            length: None,
+            _label: PhantomData,
+            unlock_fn: Some(mlock::unlock::<S>),
+        })
+    }
+}
+
+impl<S: Zeroize, L: SecretLabel> SecretBox<S, L> {
+    /// Create a secret value tagged with an explicit [`SecretLabel`], using a
+    /// pre-boxed value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secret_box::{SecretBox, SecretLabel};
+    ///
+    /// struct ApiKey;
+    /// impl SecretLabel for ApiKey {}
+    ///
+    /// let secret: SecretBox<String, ApiKey> =
+    ///     SecretBox::new_labeled(Box::new("password".to_string()));
+    /// ```
+    pub fn new_labeled(boxed: Box<S>) -> Self {
         Self {
             inner: boxed,
             length: None,
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
         }
     }
 }
@@ -86,7 +233,10 @@ impl<S: Zeroize + Default> SecretBox<S> {
     /// Create a secret value by initializing it in-place on the heap.
     ///
     /// This is the safest construction method as the secret value never
-    /// exists on the stack - it's initialized directly on the heap.
+    /// exists on the stack - it's initialized directly on the heap. The
+    /// secret is tagged with the default `()` label; use
+    /// [`SecretBox::init_with_mut_labeled`] to tag it with a specific
+    /// [`SecretLabel`] instead.
     ///
     /// # Example
     ///
@@ -100,50 +250,167 @@ impl<S: Zeroize + Default> SecretBox<S> {
     /// assert_eq!(secret.expose_secret(), b"secret_bytes");
     /// ```
     pub fn init_with_mut(f: impl FnOnce(&mut S)) -> Self {
+        Self::init_with_mut_labeled(f)
+    }
+}
+
+impl<S: Zeroize + Default, L: SecretLabel> SecretBox<S, L> {
+    /// Create a secret value tagged with an explicit [`SecretLabel`],
+    /// initializing it in-place on the heap.
+    pub fn init_with_mut_labeled(f: impl FnOnce(&mut S)) -> Self {
         let mut secret = Self {
             inner: Box::default(),
             length: None,
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
         };
         f(&mut secret.inner);
         secret
     }
 }
 
-impl<S: Zeroize> Zeroize for SecretBox<S> {
+impl<S: Zeroize, L: SecretLabel> Zeroize for SecretBox<S, L> {
     fn zeroize(&mut self) {
         self.inner.zeroize()
     }
 }
 
-impl<S: Zeroize> Drop for SecretBox<S> {
+impl<S: Zeroize, L: SecretLabel> Drop for SecretBox<S, L> {
     fn drop(&mut self) {
+        #[cfg(feature = "mlock")]
+        if let Some(unlock_fn) = self.unlock_fn {
+            unlock_fn(&self.inner);
+        }
         self.zeroize()
     }
 }
 
-impl<S: Zeroize> ZeroizeOnDrop for SecretBox<S> {}
+impl<S: Zeroize, L: SecretLabel> ZeroizeOnDrop for SecretBox<S, L> {}
 
-impl<S: Zeroize> Debug for SecretBox<S> {
+/// Marker trait for secret types which may be cloned.
+///
+/// By default, `SecretBox<T>` does NOT implement `Clone` to prevent secrets
+/// from being silently duplicated. To allow cloning, implement this marker
+/// trait on `T`:
+///
+/// ```
+/// use secret_box::CloneableSecret;
+/// use zeroize::Zeroize;
+///
+/// #[derive(Clone, Zeroize)]
+/// struct MySecret {
+///     key: String,
+/// }
+///
+/// impl CloneableSecret for MySecret {}
+/// ```
+#[cfg(feature = "cloneable-secret")]
+pub trait CloneableSecret: Clone + Zeroize {}
+
+/// Note: if the original was pinned with [`SecretBox::new_locked`], the clone
+/// is a separate heap allocation and is not `mlock`'d.
+#[cfg(feature = "cloneable-secret")]
+impl<S, L> Clone for SecretBox<S, L>
+where
+    S: CloneableSecret,
+    L: SecretLabel,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            length: self.length,
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
+        }
+    }
+}
+
+impl<S: Zeroize, L: SecretLabel> Debug for SecretBox<S, L> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = any::type_name::<L>();
+        if label == any::type_name::<()>() {
+            write!(f, "SecretBox<{}>", any::type_name::<S>())?;
+        } else {
+            write!(f, "SecretBox<{} as {}>", any::type_name::<S>(), label)?;
+        }
         match self.length {
             Some(len) => {
-                write!(f, "SecretBox<{}>(", any::type_name::<S>())?;
+                write!(f, "(")?;
                 for _ in 0..len {
                     write!(f, "*")?;
                 }
                 write!(f, ")")
             }
-            None => write!(f, "SecretBox<{}>([REDACTED])", any::type_name::<S>()),
+            None => write!(f, "([REDACTED])"),
         }
     }
 }
 
-impl<S: Zeroize> From<Box<S>> for SecretBox<S> {
+impl<S: Zeroize, L: SecretLabel> fmt::Display for SecretBox<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "**REDACTED**")
+    }
+}
+
+impl<S: Zeroize, L: SecretLabel> From<Box<S>> for SecretBox<S, L> {
     fn from(boxed: Box<S>) -> Self {
-        Self::new(boxed)
+        Self::new_labeled(boxed)
     }
 }
 
+/// A [`SecretBox`] specialized for `String` secrets.
+pub type SecretString = SecretBox<String>;
+
+impl SecretString {
+    /// Create a [`SecretString`] by copying a `&str` onto the heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secret_box::{SecretString, ExposeSecret};
+    ///
+    /// let password = SecretString::from_str("super_secret");
+    /// assert_eq!(password.expose_secret(), "super_secret");
+    /// ```
+    // Intentionally not `FromStr::from_str`: this copy is infallible and
+    // FromStr's `Result`-returning signature doesn't fit.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Self {
+        String::from(s).into()
+    }
+}
+
+/// A [`SecretBox`] specialized for `Vec<T>` secrets.
+pub type SecretVec<T> = SecretBox<Vec<T>>;
+
+impl<T: Zeroize + Clone> SecretVec<T> {
+    /// Create a [`SecretVec`] by copying a slice onto the heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secret_box::{SecretBytes, ExposeSecret};
+    ///
+    /// // `SecretBytes` is just `SecretVec<u8>`, so it gets this constructor too.
+    /// let key = SecretBytes::from_slice(b"secret_key");
+    /// assert_eq!(key.expose_secret(), b"secret_key");
+    /// ```
+    pub fn from_slice(items: &[T]) -> Self {
+        Self {
+            inner: Box::new(items.to_vec()),
+            length: Some(items.len()),
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
+        }
+    }
+}
+
+/// A [`SecretBox`] specialized for byte-vector secrets.
+pub type SecretBytes = SecretBox<Vec<u8>>;
+
 /// Expose a reference to an inner secret.
 ///
 /// This trait provides the only method for accessing a secret value,
@@ -155,28 +422,66 @@ pub trait ExposeSecret<S> {
     fn expose_secret(&self) -> &S;
 }
 
-impl<S: Zeroize> ExposeSecret<S> for SecretBox<S> {
+impl<S: Zeroize, L: SecretLabel> ExposeSecret<S> for SecretBox<S, L> {
     fn expose_secret(&self) -> &S {
         &self.inner
     }
 }
 
-impl From<String> for SecretBox<String> {
+/// Expose a mutable reference to an inner secret.
+///
+/// This lets callers mutate a secret in place (e.g. re-key, rotate, or
+/// decrypt-in-buffer) without moving it out and risking stack copies.
+///
+/// Note: if the `mlock` feature is enabled and the secret was created with
+/// [`SecretBox::new_locked`], mutating it this way can silently invalidate
+/// the `mlock(2)` guarantee; see the caveat on `new_locked`.
+///
+/// # Example
+///
+/// ```
+/// use secret_box::{SecretBox, ExposeSecret, ExposeSecretMut};
+///
+/// let mut secret: SecretBox<String> = "old_password".to_string().into();
+/// secret.expose_secret_mut().push_str("_rotated");
+/// assert_eq!(secret.expose_secret(), "old_password_rotated");
+/// ```
+pub trait ExposeSecretMut<S> {
+    /// Expose a mutable reference to the secret value.
+    fn expose_secret_mut(&mut self) -> &mut S;
+}
+
+impl<S: Zeroize, L: SecretLabel> ExposeSecretMut<S> for SecretBox<S, L> {
+    fn expose_secret_mut(&mut self) -> &mut S {
+        // The caller may change the value's length, so the cached length used
+        // by `Debug` can no longer be trusted and must be invalidated.
+        self.length = None;
+        &mut self.inner
+    }
+}
+
+impl<L: SecretLabel> From<String> for SecretBox<String, L> {
     fn from(s: String) -> Self {
         let length = s.len();
         Self {
             inner: Box::new(s),
             length: Some(length),
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
         }
     }
 }
 
-impl From<Vec<u8>> for SecretBox<Vec<u8>> {
+impl<L: SecretLabel> From<Vec<u8>> for SecretBox<Vec<u8>, L> {
     fn from(v: Vec<u8>) -> Self {
         let length = v.len();
         Self {
             inner: Box::new(v),
             length: Some(length),
+            _label: PhantomData,
+            #[cfg(feature = "mlock")]
+            unlock_fn: None,
         }
     }
 }
@@ -204,23 +509,25 @@ impl From<Vec<u8>> for SecretBox<Vec<u8>> {
 pub trait SerializableSecret: Serialize {}
 
 #[cfg(feature = "serde")]
-impl<'de, T> Deserialize<'de> for SecretBox<T>
+impl<'de, T, L> Deserialize<'de> for SecretBox<T, L>
 where
     T: Zeroize + DeserializeOwned,
+    L: SecretLabel,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let value = T::deserialize(deserializer)?;
-        Ok(Self::new(Box::new(value)))
+        Ok(Self::new_labeled(Box::new(value)))
     }
 }
 
 #[cfg(feature = "serde")]
-impl<T> Serialize for SecretBox<T>
+impl<T, L> Serialize for SecretBox<T, L>
 where
     T: Zeroize + SerializableSecret,
+    L: SecretLabel,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -230,6 +537,128 @@ where
     }
 }
 
+/// Wrapper type that opts a single value into serialization at the call site,
+/// rather than requiring its type to implement [`SerializableSecret`] globally.
+///
+/// Wrap a reference to the secret you intend to serialize: `SerdeSecret(&secret)`.
+/// This keeps every intentional serialization grep-able (`SerdeSecret(`) and lets
+/// callers serialize types they don't own and so cannot add a marker impl to,
+/// without making every `SecretBox<T>` in the program serializable.
+///
+/// # Example
+///
+/// ```
+/// use secret_box::{SecretBox, SerdeSecret};
+///
+/// let secret: SecretBox<String> = "api_key".to_string().into();
+/// let json = serde_json::to_string(&SerdeSecret(&secret)).unwrap();
+/// assert_eq!(json, "\"api_key\"");
+/// ```
+#[cfg(feature = "serde")]
+pub struct SerdeSecret<S>(pub S);
+
+#[cfg(feature = "serde")]
+impl<S, L> Serialize for SerdeSecret<&SecretBox<S, L>>
+where
+    S: Zeroize + Serialize,
+    L: SecretLabel,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        self.0.inner.serialize(serializer)
+    }
+}
+
+/// A secret whose exposure count is capped at compile time.
+///
+/// Unlike [`SecretBox`], which lets callers invoke [`ExposeSecret::expose_secret`]
+/// an unlimited number of times, `ExposableSecret<S, MEC>` only allows the secret
+/// to be read up to `MEC` ("max exposure count") times along any single code
+/// path. Each call to [`expose_secret`](Self::expose_secret) consumes `self` and
+/// returns a new `ExposableSecret` with the exposure count `EC` incremented by
+/// one, so the original binding cannot be reused to read the secret again - a
+/// further call on a binding already exposed `MEC` times fails to compile rather
+/// than panicking at runtime.
+///
+/// This requires the nightly-only `generic_const_exprs` language feature, so it
+/// is gated behind the `exposable-secret` Cargo feature and does not affect the
+/// default `no_std`, stable-Rust build.
+///
+/// # Example
+///
+/// ```
+/// use secret_box::ExposableSecret;
+///
+/// let secret: ExposableSecret<u32, 2> = ExposableSecret::new(|| 42);
+/// let (secret, value) = secret.expose_secret(|v| *v);
+/// assert_eq!(value, 42);
+///
+/// let (_secret, value) = secret.expose_secret(|v| *v);
+/// assert_eq!(value, 42);
+///
+/// // `_secret` has now been exposed twice, so a third call is a compile error:
+/// // _secret.expose_secret(|v| *v);
+/// ```
+#[cfg(feature = "exposable-secret")]
+pub struct ExposableSecret<S: Zeroize, const MEC: usize, const EC: usize = 0> {
+    inner: Option<S>,
+}
+
+#[cfg(feature = "exposable-secret")]
+impl<S: Zeroize, const MEC: usize> ExposableSecret<S, MEC, 0> {
+    /// Create a secret value by initializing it in a closure.
+    ///
+    /// The returned value starts with an exposure count of zero.
+    pub fn new(f: impl FnOnce() -> S) -> Self {
+        Self { inner: Some(f()) }
+    }
+}
+
+#[cfg(feature = "exposable-secret")]
+impl<S: Zeroize, const MEC: usize, const EC: usize> ExposableSecret<S, MEC, EC> {
+    /// Expose the secret to `f`, consuming `self` and returning a new
+    /// `ExposableSecret` with the exposure count incremented by one, along with
+    /// `f`'s result.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `EC >= MEC`, i.e. if this binding has already been
+    /// exposed `MEC` times.
+    pub fn expose_secret<R>(
+        mut self,
+        f: impl FnOnce(&S) -> R,
+    ) -> (ExposableSecret<S, MEC, { EC + 1 }>, R) {
+        const { assert_exposure_in_bounds::<EC, MEC>() };
+        let inner = self.inner.take().expect("secret already exposed");
+        let result = f(&inner);
+        (ExposableSecret { inner: Some(inner) }, result)
+    }
+}
+
+#[cfg(feature = "exposable-secret")]
+const fn assert_exposure_in_bounds<const EC: usize, const MEC: usize>() {
+    assert!(EC < MEC, "secret exposed more times than its MEC allows");
+}
+
+#[cfg(feature = "exposable-secret")]
+impl<S: Zeroize, const MEC: usize, const EC: usize> Zeroize for ExposableSecret<S, MEC, EC> {
+    fn zeroize(&mut self) {
+        self.inner.zeroize()
+    }
+}
+
+#[cfg(feature = "exposable-secret")]
+impl<S: Zeroize, const MEC: usize, const EC: usize> Drop for ExposableSecret<S, MEC, EC> {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+#[cfg(feature = "exposable-secret")]
+impl<S: Zeroize, const MEC: usize, const EC: usize> ZeroizeOnDrop for ExposableSecret<S, MEC, EC> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
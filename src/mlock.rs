@@ -0,0 +1,94 @@
+//! `mlock(2)`-backed memory protection for secrets, enabled via the `mlock`
+//! feature.
+//!
+//! The crate forbids unsafe code everywhere else; this module is the one,
+//! narrow exception, scoped to the raw `mlock`/`munlock` FFI calls needed to
+//! pin a secret's heap allocation so it cannot be paged to swap.
+#![allow(unsafe_code)]
+
+use std::fmt;
+use std::os::raw::c_void;
+use zeroize::Zeroize;
+
+/// Error returned when [`SecretBox::new_locked`](crate::SecretBox::new_locked)
+/// fails to pin a secret's memory with `mlock(2)`.
+///
+/// This commonly happens when the process has already locked as much memory
+/// as its `RLIMIT_MEMLOCK` allows.
+#[derive(Debug)]
+pub struct MlockError(std::io::Error);
+
+impl fmt::Display for MlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mlock(2) failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for MlockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Marker trait for types whose secret bytes [`SecretBox::new_locked`](crate::SecretBox::new_locked)
+/// knows how to pin with `mlock(2)`.
+///
+/// The default `lock_region` pins `Self`'s own inline representation, which
+/// is correct for plain, fixed-size types with no separate heap allocation.
+/// Types that store their real data elsewhere - like `String` or `Vec<u8>`,
+/// both implemented below - must override it to point at that allocation
+/// instead; otherwise `mlock(2)` only pins an irrelevant header and the
+/// actual secret bytes remain swappable.
+pub trait Lockable: Zeroize {
+    /// Returns the pointer and length of the region `new_locked` should pin.
+    fn lock_region(&self) -> (*const u8, usize)
+    where
+        Self: Sized,
+    {
+        (self as *const Self as *const u8, size_of::<Self>())
+    }
+}
+
+impl Lockable for String {
+    fn lock_region(&self) -> (*const u8, usize) {
+        (self.as_ptr(), self.len())
+    }
+}
+
+impl Lockable for Vec<u8> {
+    fn lock_region(&self) -> (*const u8, usize) {
+        (self.as_ptr(), self.len())
+    }
+}
+
+pub(crate) fn lock<T: Lockable>(value: &T) -> Result<(), MlockError> {
+    let (ptr, len) = value.lock_region();
+    if len == 0 {
+        // An empty `String`/`Vec<u8>` has no backing allocation - `as_ptr()`
+        // returns a dangling, non-null marker address that isn't a real
+        // mapping, so `mlock(2)` on it fails (e.g. `ENOMEM`) despite there
+        // being nothing to protect. Skip the syscall entirely.
+        return Ok(());
+    }
+    // SAFETY: `ptr` points to `len` bytes that are valid for reads for the
+    // lifetime of `value`, which outlives this call.
+    let result = unsafe { libc::mlock(ptr as *const c_void, len) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(MlockError(std::io::Error::last_os_error()))
+    }
+}
+
+pub(crate) fn unlock<T: Lockable>(value: &T) {
+    let (ptr, len) = value.lock_region();
+    if len == 0 {
+        return;
+    }
+    // SAFETY: `ptr`/`len` describe the same region previously passed to
+    // `lock`. A failing `munlock` doesn't affect memory safety here, so its
+    // result is intentionally ignored.
+    unsafe {
+        libc::munlock(ptr as *const c_void, len);
+    }
+}
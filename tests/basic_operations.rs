@@ -3,7 +3,7 @@
 mod common;
 
 use common::{assert_debug_redacted, TEST_SECRET};
-use secret_box::{ExposeSecret, SecretBox};
+use secret_box::{ExposeSecret, ExposeSecretMut, SecretBox, SecretBytes, SecretString, SecretVec};
 use zeroize::Zeroize;
 
 #[test]
@@ -115,3 +115,52 @@ fn test_empty_vec_secret() {
     let secret: SecretBox<Vec<u8>> = SecretBox::new(Box::new(Vec::new()));
     assert!(secret.expose_secret().is_empty());
 }
+
+#[test]
+fn test_expose_secret_mut_allows_in_place_mutation() {
+    let mut secret: SecretBox<String> = TEST_SECRET.to_string().into();
+    secret.expose_secret_mut().push_str("_rotated");
+    assert_eq!(secret.expose_secret(), &format!("{TEST_SECRET}_rotated"));
+}
+
+#[test]
+fn test_expose_secret_mut_invalidates_cached_length() {
+    let mut secret: SecretBox<String> = "short".to_string().into();
+    secret.expose_secret_mut().push_str("_much_longer_now");
+
+    // The cached asterisk-length is invalidated by a mutable exposure, so
+    // Debug falls back to [REDACTED] rather than showing a stale count.
+    let debug_str = format!("{:?}", secret);
+    assert!(debug_str.contains("REDACTED"));
+}
+
+#[test]
+fn test_secret_string_from_str() {
+    let secret = SecretString::from_str(TEST_SECRET);
+    assert_eq!(secret.expose_secret(), TEST_SECRET);
+}
+
+#[test]
+fn test_secret_vec_from_slice() {
+    let secret: SecretVec<u8> = SecretVec::from_slice(&[1, 2, 3]);
+    assert_eq!(secret.expose_secret(), &[1, 2, 3]);
+}
+
+#[test]
+fn test_secret_bytes_is_secret_vec_of_u8() {
+    let secret: SecretBytes = SecretBytes::from_slice(b"secret_key");
+    assert_eq!(secret.expose_secret(), b"secret_key");
+}
+
+#[test]
+fn test_secret_vec_from_slice_debug_shows_known_length() {
+    let secret: SecretVec<u8> = SecretVec::from_slice(&[1, 2, 3]);
+    let debug_str = format!("{:?}", secret);
+    assert!(!debug_str.contains("[REDACTED]"));
+}
+
+#[test]
+fn test_display_always_shows_fixed_redaction_token() {
+    let secret: SecretString = TEST_SECRET.to_string().into();
+    assert_eq!(format!("{secret}"), "**REDACTED**");
+}
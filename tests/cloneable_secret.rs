@@ -0,0 +1,32 @@
+//! Opt-in secret cloning tests
+#![cfg(feature = "cloneable-secret")]
+
+use secret_box::{CloneableSecret, ExposeSecret, SecretBox};
+use zeroize::Zeroize;
+
+#[derive(Clone, Default, PartialEq, Debug, Zeroize)]
+struct ApiKey(String);
+
+impl CloneableSecret for ApiKey {}
+
+#[test]
+fn test_clone_preserves_exposed_value() {
+    let secret: SecretBox<ApiKey> = SecretBox::new(Box::new(ApiKey("super_secret".to_string())));
+    let clone = secret.clone();
+    assert_eq!(clone.expose_secret(), secret.expose_secret());
+}
+
+#[test]
+fn test_clone_preserves_debug_length() {
+    let secret: SecretBox<ApiKey> = SecretBox::new(Box::new(ApiKey("super_secret".to_string())));
+    let clone = secret.clone();
+    assert_eq!(format!("{:?}", secret), format!("{:?}", clone));
+}
+
+#[test]
+fn test_clones_are_independent() {
+    let mut secret: SecretBox<ApiKey> = SecretBox::new(Box::new(ApiKey("original".to_string())));
+    let clone = secret.clone();
+    secret.zeroize();
+    assert_eq!(clone.expose_secret(), &ApiKey("original".to_string()));
+}
@@ -0,0 +1,21 @@
+//! Compile-time exposure-count limiting tests
+#![cfg(feature = "exposable-secret")]
+
+use secret_box::ExposableSecret;
+
+#[test]
+fn test_expose_secret_returns_value_and_incremented_binding() {
+    let secret: ExposableSecret<u32, 2> = ExposableSecret::new(|| 42);
+    let (secret, value) = secret.expose_secret(|v| *v);
+    assert_eq!(value, 42);
+
+    let (_secret, value) = secret.expose_secret(|v| *v);
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn test_expose_secret_with_string() {
+    let secret: ExposableSecret<String, 1> = ExposableSecret::new(|| "super_secret".to_string());
+    let (_secret, len) = secret.expose_secret(|s| s.len());
+    assert_eq!(len, "super_secret".len());
+}
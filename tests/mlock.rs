@@ -0,0 +1,66 @@
+//! Memory-locking tests
+#![cfg(feature = "mlock")]
+
+use secret_box::{ExposeSecret, Lockable, SecretBox, SecretLabel};
+
+struct ApiKey;
+impl SecretLabel for ApiKey {}
+
+#[test]
+fn test_new_locked_exposes_inner_value() {
+    let secret = SecretBox::new_locked(Box::new("super_secret".to_string()))
+        .expect("mlock should succeed in test environments");
+    assert_eq!(secret.expose_secret(), "super_secret");
+}
+
+#[test]
+fn test_new_locked_is_dropped_without_panicking() {
+    let secret = SecretBox::new_locked(Box::new(vec![1u8, 2, 3]))
+        .expect("mlock should succeed in test environments");
+    drop(secret);
+}
+
+#[test]
+fn test_new_locked_labeled_exposes_inner_value() {
+    let secret: SecretBox<String, ApiKey> =
+        SecretBox::new_locked_labeled(Box::new("super_secret".to_string()))
+            .expect("mlock should succeed in test environments");
+    assert_eq!(secret.expose_secret(), "super_secret");
+}
+
+#[test]
+fn test_string_lock_region_covers_its_buffer_not_its_header() {
+    // A `String`'s own inline representation (ptr/len/cap) is a fixed-size
+    // header; its secret bytes live in a separate heap allocation. Locking
+    // must pin that allocation, not the header.
+    let s = "x".repeat(100_000);
+    let (ptr, len) = s.lock_region();
+    assert_eq!(len, s.len());
+    assert_eq!(ptr, s.as_ptr());
+    assert_ne!(len, std::mem::size_of::<String>());
+}
+
+#[test]
+fn test_new_locked_succeeds_for_empty_string() {
+    // An empty `String`'s `as_ptr()` is a dangling marker, not a real
+    // mapping; locking it must be a no-op rather than an mlock(2) error.
+    let secret = SecretBox::new_locked(Box::new(String::new()))
+        .expect("locking an empty String should be a no-op, not an error");
+    assert_eq!(secret.expose_secret(), "");
+}
+
+#[test]
+fn test_new_locked_succeeds_for_empty_vec() {
+    let secret = SecretBox::new_locked(Box::new(Vec::<u8>::new()))
+        .expect("locking an empty Vec should be a no-op, not an error");
+    assert_eq!(secret.expose_secret(), &Vec::<u8>::new());
+}
+
+#[test]
+fn test_vec_lock_region_covers_its_buffer_not_its_header() {
+    let v = vec![0u8; 100_000];
+    let (ptr, len) = v.lock_region();
+    assert_eq!(len, v.len());
+    assert_eq!(ptr, v.as_ptr());
+    assert_ne!(len, std::mem::size_of::<Vec<u8>>());
+}
@@ -0,0 +1,43 @@
+//! Phantom secret-label tests
+
+use secret_box::{ExposeSecret, SecretBox, SecretLabel};
+
+struct ApiKey;
+impl SecretLabel for ApiKey {}
+
+struct DatabasePassword;
+impl SecretLabel for DatabasePassword {}
+
+#[test]
+fn test_labeled_secret_exposes_inner_value() {
+    let key: SecretBox<String, ApiKey> = "my_api_key".to_string().into();
+    assert_eq!(key.expose_secret(), "my_api_key");
+}
+
+#[test]
+fn test_default_label_still_works_unannotated() {
+    let secret = SecretBox::new(Box::new("super_secret".to_string()));
+    assert_eq!(secret.expose_secret(), "super_secret");
+}
+
+#[test]
+fn test_new_labeled_constructor() {
+    let password: SecretBox<String, DatabasePassword> =
+        SecretBox::new_labeled(Box::new("hunter2".to_string()));
+    assert_eq!(password.expose_secret(), "hunter2");
+}
+
+#[test]
+fn test_debug_includes_label_type_name() {
+    let key: SecretBox<String, ApiKey> = "my_api_key".to_string().into();
+    let debug_str = format!("{:?}", key);
+    assert!(debug_str.contains("ApiKey"));
+    assert!(debug_str.contains("as"));
+}
+
+#[test]
+fn test_debug_omits_default_label() {
+    let secret: SecretBox<String> = "my_password".to_string().into();
+    let debug_str = format!("{:?}", secret);
+    assert!(!debug_str.contains(" as "));
+}
@@ -7,7 +7,7 @@
 mod common;
 
 use common::TestSerializable;
-use secret_box::{ExposeSecret, SecretBox};
+use secret_box::{ExposeSecret, SecretBox, SerdeSecret};
 use serde::Deserialize;
 
 #[test]
@@ -87,6 +87,21 @@ fn test_deserialize_vec() {
     assert_eq!(secret.expose_secret(), &[1, 2, 3, 4, 5]);
 }
 
+#[test]
+fn test_serde_secret_serializes_without_marker_trait() {
+    let secret: SecretBox<String> = "serde_secret_value".to_string().into();
+    let json = serde_json::to_string(&SerdeSecret(&secret)).unwrap();
+    assert!(json.contains("serde_secret_value"));
+}
+
+#[test]
+fn test_serde_secret_round_trip() {
+    let secret: SecretBox<Vec<u8>> = vec![1, 2, 3].into();
+    let json = serde_json::to_string(&SerdeSecret(&secret)).unwrap();
+    let restored: SecretBox<Vec<u8>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.expose_secret(), secret.expose_secret());
+}
+
 #[test]
 fn test_debug_still_redacts_after_deserialize() {
     let json = r#""super_secret_value""#;